@@ -12,9 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::rc::Rc;
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
-use cust::prelude::*;
+use cust::{
+    context::Context,
+    device::Device,
+    link::{JitOption, Linker, OptLevel},
+    memory::LockedBuffer,
+    prelude::*,
+};
 use risc0_core::field::{
     baby_bear::{BabyBearElem, BabyBearExtElem},
     map_pow, Elem, ExtElem, RootsOfUnity,
@@ -32,78 +43,297 @@ use risc0_zkp::{
 };
 
 use crate::{
-    prove::{engine::SegmentProverImpl, SegmentProver},
+    prove::{engine::SegmentProverImpl, Segment, SegmentProver, SegmentReceipt},
     GLOBAL_MIX, GLOBAL_OUT, REGISTER_GROUP_ACCUM, REGISTER_GROUP_CTRL, REGISTER_GROUP_DATA,
 };
 
 const KERNELS_FATBIN: &[u8] = include_bytes!(env!("RV32IM_CUDA_PATH"));
+const KERNELS_PTX: &str = include_str!(env!("RV32IM_CUDA_PTX_PATH"));
+
+/// Loads the `eval_check` module for `hal`'s device: the baked fatbin if it
+/// already contains a cubin for this GPU's compute capability, otherwise
+/// JIT-compiles the embedded PTX for the detected architecture and caches
+/// the resulting cubin on disk so the next run on the same device skips
+/// the JIT step entirely.
+fn load_module<CH: CudaHash>(hal: &CudaHal<CH>) -> anyhow::Result<Module> {
+    match Module::from_fatbin(KERNELS_FATBIN, &[]) {
+        Ok(module) => Ok(module),
+        Err(fatbin_err) => {
+            tracing::debug!(
+                "fatbin has no cubin for this device ({fatbin_err}); JIT-compiling PTX instead"
+            );
+            jit_compile_module(hal)
+        }
+    }
+}
+
+fn jit_compile_module<CH: CudaHash>(hal: &CudaHal<CH>) -> anyhow::Result<Module> {
+    let device = hal.device();
+    let (major, minor) = device.compute_capability()?;
+    let cache_path = cubin_cache_path(major, minor);
+
+    if let Some(cache_path) = &cache_path {
+        if let Ok(cubin) = std::fs::read(cache_path) {
+            tracing::debug!("loading cached cubin from {}", cache_path.display());
+            return Ok(Module::from_cubin(&cubin, &[])?);
+        }
+    }
+
+    tracing::info!("JIT-compiling eval_check PTX for sm_{major}{minor}");
+    let mut linker = Linker::new()?;
+    linker.add_ptx(
+        KERNELS_PTX,
+        "eval_check",
+        &[
+            JitOption::OptLevel(OptLevel::O4),
+            JitOption::TargetFromContext,
+        ],
+    )?;
+    let cubin = linker.complete()?;
+
+    if let Some(cache_path) = &cache_path {
+        cache_cubin(cache_path, cubin.as_ref());
+    }
+
+    Ok(Module::from_cubin(cubin.as_ref(), &[])?)
+}
+
+/// Writes `cubin` to `cache_path` via a write-then-rename so that a
+/// concurrent reader (another `GpuWorker` thread JIT-compiling the same
+/// arch at the same time) never observes a partially-written file. Each
+/// writer uses its own uniquely-named temp file, so racing writers don't
+/// corrupt each other's output either; since they're compiling the same
+/// PTX for the same arch, whichever rename lands last just overwrites an
+/// identical file.
+fn cache_cubin(cache_path: &std::path::Path, cubin: &[u8]) {
+    static TMP_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+    let tmp_path = cache_path.with_extension(format!(
+        "cubin.tmp.{}.{}",
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    ));
+    if let Err(err) = std::fs::write(&tmp_path, cubin) {
+        tracing::warn!("failed to write cubin cache tmp file {tmp_path:?}: {err}");
+        return;
+    }
+    if let Err(err) = std::fs::rename(&tmp_path, cache_path) {
+        tracing::warn!("failed to install cached cubin at {cache_path:?}: {err}");
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+}
+
+/// Cache path for a JIT-compiled cubin, keyed by compute capability and a
+/// hash of the embedded PTX so a PTX update invalidates stale cubins.
+/// Returns `None` (disabling the on-disk cache for this run, not failing
+/// the JIT) if the cache directory can't be confirmed private to the
+/// current user — see [`ensure_private_cache_dir`].
+fn cubin_cache_path(major: i32, minor: i32) -> Option<std::path::PathBuf> {
+    let dir = std::env::temp_dir().join(format!("risc0-cuda-module-cache-{}", cache_owner_tag()));
+    if let Err(err) = ensure_private_cache_dir(&dir) {
+        tracing::warn!("disabling on-disk cubin cache: {err}");
+        return None;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    KERNELS_PTX.hash(&mut hasher);
+    let kernel_hash = hasher.finish();
+
+    Some(dir.join(format!("eval_check-sm_{major}{minor}-{kernel_hash:016x}.cubin")))
+}
+
+/// A tag identifying the current OS user, so the cubin cache directory can
+/// be scoped per-user instead of shared by everyone under `/tmp`.
+fn cache_owner_tag() -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        if let Ok(meta) = std::fs::metadata("/proc/self") {
+            return meta.uid().to_string();
+        }
+    }
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Creates `dir` (if needed) and confirms it's only accessible to the
+/// current user before we trust anything read from it as a cubin to load
+/// and execute on the GPU.
+///
+/// The naive version of this cache — a fixed, deterministic path under the
+/// world-writable `std::env::temp_dir()`, keyed only by a non-randomized
+/// hash of public PTX text — lets any other local user precompute the
+/// exact path, win the race to create it first, and plant an arbitrary
+/// cubin that `jit_compile_module` would then load and execute unchecked.
+/// Scoping the directory by [`cache_owner_tag`] closes most of that, but a
+/// pre-existing directory could still be owned by someone else if an
+/// attacker won the initial creation race before this process ever ran;
+/// `create_dir_all` silently succeeds against an existing directory
+/// without checking who owns it, so we check ownership explicitly and
+/// refuse to use the cache rather than trust a directory we don't own.
+///
+/// The ownership and permission checks are made on Unix targets only; on
+/// other targets this just creates `dir` and returns `Ok`.
+fn ensure_private_cache_dir(dir: &std::path::Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+        let our_uid = std::fs::metadata("/proc/self")?.uid();
+        let dir_meta = std::fs::metadata(dir)?;
+        if dir_meta.uid() != our_uid {
+            anyhow::bail!(
+                "cache dir {dir:?} is owned by uid {}, not the current user (uid {our_uid}); \
+                 another local user may be attempting to plant a cubin here",
+                dir_meta.uid()
+            );
+        }
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))?;
+    }
+
+    Ok(())
+}
+
+/// Number of streams (and matching staging buffer sets) kept warm so that
+/// back-to-back `eval_check_async` calls don't pay stream/alloc setup cost.
+const STREAM_POOL_SIZE: usize = 4;
+
+/// Number of `u32`s in the `poly_mix` power table uploaded to the
+/// `eval_check` module's `poly_mix` global each call.
+const POLY_MIX_POWS_LEN: usize = BabyBearExtElem::EXT_SIZE * crate::info::NUM_POLY_MIX_POWERS;
+
+/// Pinned host staging buffers for the per-call constant uploads (`rou`,
+/// `po2`, `size`, `poly_mix`). An async H2D copy's source has to stay valid
+/// and unchanged until the stream completes, which a stack-local wouldn't
+/// survive since `eval_check_async` returns before waiting on the stream;
+/// pinning them here (and reusing the pinned allocations across calls via
+/// `StreamPool`) also avoids the driver falling back to a staged copy
+/// through pageable memory on every `eval_check` call.
+struct StagingSet {
+    rou: LockedBuffer<u32>,
+    po2: LockedBuffer<u32>,
+    size: LockedBuffer<u32>,
+    poly_mix: LockedBuffer<u32>,
+}
+
+impl StagingSet {
+    fn new() -> Self {
+        Self {
+            rou: LockedBuffer::new(&0u32, 1).unwrap(),
+            po2: LockedBuffer::new(&0u32, 1).unwrap(),
+            size: LockedBuffer::new(&0u32, 1).unwrap(),
+            poly_mix: LockedBuffer::new(&0u32, POLY_MIX_POWS_LEN).unwrap(),
+        }
+    }
+}
+
+/// A small pool of reusable CUDA streams paired with pinned staging
+/// buffers, so that launching `eval_check` on many segments in a row
+/// doesn't serialize on stream creation or per-call pinned allocations.
+struct StreamPool {
+    entries: RefCell<Vec<(Stream, StagingSet)>>,
+}
+
+impl StreamPool {
+    fn new() -> Self {
+        Self {
+            entries: RefCell::new(Vec::with_capacity(STREAM_POOL_SIZE)),
+        }
+    }
+
+    fn acquire(&self) -> (Stream, StagingSet) {
+        self.entries.borrow_mut().pop().unwrap_or_else(|| {
+            let stream = Stream::new(StreamFlags::NON_BLOCKING, None).unwrap();
+            (stream, StagingSet::new())
+        })
+    }
+
+    fn release(&self, entry: (Stream, StagingSet)) {
+        let mut entries = self.entries.borrow_mut();
+        if entries.len() < STREAM_POOL_SIZE {
+            entries.push(entry);
+        }
+    }
+}
 
 pub struct CudaCircuitHal<CH: CudaHash> {
     hal: Rc<CudaHal<CH>>, // retain a reference to ensure the context remains valid
     module: Module,
+    streams: StreamPool,
 }
 
 impl<CH: CudaHash> CudaCircuitHal<CH> {
+    /// Loads the `eval_check` module for `hal`'s device, falling back to a
+    /// runtime PTX JIT (see [`load_module`]) when the baked fatbin doesn't
+    /// cover this GPU's compute capability.
     #[tracing::instrument(name = "CudaCircuitHal::new", skip_all)]
-    pub fn new(hal: Rc<CudaHal<CH>>) -> Self {
-        let module = Module::from_fatbin(KERNELS_FATBIN, &[]).unwrap();
-        Self { hal, module }
+    pub fn new(hal: Rc<CudaHal<CH>>) -> anyhow::Result<Self> {
+        let module = load_module(&hal)?;
+        Ok(Self {
+            hal,
+            module,
+            streams: StreamPool::new(),
+        })
     }
-}
 
-impl<'a, CH: CudaHash> CircuitHal<CudaHal<CH>> for CudaCircuitHal<CH> {
+    /// Async counterpart to [`CircuitHal::eval_check`]: issues the constant
+    /// uploads and the `eval_check` kernel launch on a pool-owned stream
+    /// without synchronizing. `CircuitHal::eval_check` itself still waits
+    /// before returning, since callers are entitled to assume `check` is
+    /// populated as soon as that (synchronous) trait method returns; this
+    /// is exposed separately so a caller that tracks its own segment
+    /// pipeline — i.e. one that doesn't need `check` ready until later —
+    /// can issue segment N+1's upload/launch while it's still consuming
+    /// segment N's result. No such caller exists in this crate yet; today
+    /// this only buys per-call uploads/launch overlap plus the stream- and
+    /// pinned-buffer pooling below. Call [`EvalCheckHandle::wait`] to
+    /// observe completion; dropping the handle without waiting is a bug
+    /// (the stream would never be returned to the pool), so it asserts in
+    /// debug builds.
     #[tracing::instrument(skip_all)]
-    fn eval_check(
-        &self,
+    pub fn eval_check_async<'a>(
+        &'a self,
         check: &CudaBuffer<BabyBearElem>,
         groups: &[&CudaBuffer<BabyBearElem>],
         globals: &[&CudaBuffer<BabyBearElem>],
         poly_mix: BabyBearExtElem,
         po2: usize,
         steps: usize,
-    ) {
+    ) -> EvalCheckHandle<'a, CH> {
         let ctrl = groups[REGISTER_GROUP_CTRL];
         let data = groups[REGISTER_GROUP_DATA];
         let accum = groups[REGISTER_GROUP_ACCUM];
         let mix = globals[GLOBAL_MIX];
         let out = globals[GLOBAL_OUT];
-        tracing::debug!(
-            "check: {}, ctrl: {}, data: {}, accum: {}, mix: {} out: {}",
-            check.size(),
-            ctrl.size(),
-            data.size(),
-            accum.size(),
-            mix.size(),
-            out.size()
-        );
-        tracing::debug!(
-            "total: {}",
-            (check.size() + ctrl.size() + data.size() + accum.size() + mix.size() + out.size()) * 4
-        );
 
         const EXP_PO2: usize = log2_ceil(INV_RATE);
         let domain = steps * INV_RATE;
         let rou = BabyBearElem::ROU_FWD[po2 + EXP_PO2];
 
-        let rou = self.hal.copy_from_elem("rou", &[rou]);
-        let po2 = self.hal.copy_from_u32("po2", &[po2 as u32]);
-        let size = self.hal.copy_from_u32("size", &[domain as u32]);
+        let (stream, mut staging) = self.streams.acquire();
+
+        staging.rou[0] = BabyBearElem::as_u32_slice(&[rou])[0];
+        staging.po2[0] = po2 as u32;
+        staging.size[0] = domain as u32;
+
+        let rou = DeviceBuffer::from_slice_async(&staging.rou, &stream).unwrap();
+        let po2 = DeviceBuffer::from_slice_async(&staging.po2, &stream).unwrap();
+        let size = DeviceBuffer::from_slice_async(&staging.size, &stream).unwrap();
 
         let poly_mix_pows = map_pow(poly_mix, crate::info::POLY_MIX_POWERS);
-        let poly_mix_pows: &[u32; BabyBearExtElem::EXT_SIZE * crate::info::NUM_POLY_MIX_POWERS] =
-            BabyBearExtElem::as_u32_slice(poly_mix_pows.as_slice())
-                .try_into()
-                .unwrap();
+        staging
+            .poly_mix
+            .copy_from_slice(BabyBearExtElem::as_u32_slice(poly_mix_pows.as_slice()));
 
         let mix_pows_name = std::ffi::CString::new("poly_mix").unwrap();
         self.module
             .get_global(&mix_pows_name)
             .unwrap()
-            .copy_from(poly_mix_pows)
+            .async_copy_from(&staging.poly_mix, &stream)
             .unwrap();
 
-        let stream = Stream::new(StreamFlags::DEFAULT, None).unwrap();
-
         let kernel = self.module.get_function("eval_check").unwrap();
         let params = self.hal.compute_simple_params(domain);
         unsafe {
@@ -120,17 +350,345 @@ impl<'a, CH: CudaHash> CircuitHal<CudaHal<CH>> for CudaCircuitHal<CH> {
             ))
             .unwrap();
         }
+
+        // rou/po2/size/poly_mix are only read back in by the kernel launch
+        // and global-memory copy above, both submitted on the same stream,
+        // so `staging`'s pinned buffers are safe to reuse once this handle's
+        // stream is synchronized — which is why `staging` itself (not just
+        // the three `DeviceBuffer`s) rides along in the handle below.
+        EvalCheckHandle {
+            hal: self,
+            stream: Some((stream, staging)),
+            _uploads: (rou, po2, size),
+        }
+    }
+}
+
+/// Handle to an in-flight `eval_check` kernel launched via
+/// [`CudaCircuitHal::eval_check_async`]. Returns the owning stream (and its
+/// staging buffers) to the pool once waited on.
+pub struct EvalCheckHandle<'a, CH: CudaHash> {
+    hal: &'a CudaCircuitHal<CH>,
+    stream: Option<(Stream, StagingSet)>,
+    _uploads: (DeviceBuffer<u32>, DeviceBuffer<u32>, DeviceBuffer<u32>),
+}
+
+impl<'a, CH: CudaHash> EvalCheckHandle<'a, CH> {
+    /// Blocks until the kernel launch this handle represents has completed,
+    /// then returns the stream to the pool for reuse.
+    pub fn wait(mut self) {
+        let (stream, staging) = self.stream.take().unwrap();
         stream.synchronize().unwrap();
+        self.hal.streams.release((stream, staging));
+    }
+
+    /// True if the kernel launch has already completed, without blocking.
+    pub fn is_ready(&self) -> bool {
+        self.stream
+            .as_ref()
+            .map(|(stream, _)| stream.is_ready().unwrap_or(false))
+            .unwrap_or(true)
+    }
+}
+
+impl<'a, CH: CudaHash> Drop for EvalCheckHandle<'a, CH> {
+    fn drop(&mut self) {
+        debug_assert!(
+            self.stream.is_none(),
+            "EvalCheckHandle dropped without calling wait(); its stream would leak from the pool"
+        );
+    }
+}
+
+impl<'a, CH: CudaHash> CircuitHal<CudaHal<CH>> for CudaCircuitHal<CH> {
+    #[tracing::instrument(skip_all)]
+    fn eval_check(
+        &self,
+        check: &CudaBuffer<BabyBearElem>,
+        groups: &[&CudaBuffer<BabyBearElem>],
+        globals: &[&CudaBuffer<BabyBearElem>],
+        poly_mix: BabyBearExtElem,
+        po2: usize,
+        steps: usize,
+    ) {
+        let ctrl = groups[REGISTER_GROUP_CTRL];
+        let data = groups[REGISTER_GROUP_DATA];
+        let accum = groups[REGISTER_GROUP_ACCUM];
+        let mix = globals[GLOBAL_MIX];
+        let out = globals[GLOBAL_OUT];
+        tracing::debug!(
+            "check: {}, ctrl: {}, data: {}, accum: {}, mix: {} out: {}",
+            check.size(),
+            ctrl.size(),
+            data.size(),
+            accum.size(),
+            mix.size(),
+            out.size()
+        );
+        tracing::debug!(
+            "total: {}",
+            (check.size() + ctrl.size() + data.size() + accum.size() + mix.size() + out.size()) * 4
+        );
+
+        self.eval_check_async(check, groups, globals, poly_mix, po2, steps)
+            .wait();
     }
 }
 
 pub type CudaCircuitHalSha256 = CudaCircuitHal<CudaHashSha256>;
 pub type CudaCircuitHalPoseidon2 = CudaCircuitHal<CudaHashPoseidon2>;
 
-pub fn get_segment_prover() -> Box<dyn SegmentProver> {
+/// Picks a segment prover for the current machine: a single-GPU prover when
+/// only one CUDA device is visible (or device enumeration fails), otherwise
+/// a [`MultiGpuSegmentProver`] that fans segments out round-robin across
+/// every visible device.
+///
+/// CUDA-only: there is no OpenCL branch here, and there isn't one in
+/// `prove/hal/opencl.rs` to dispatch to either — that file is groundwork
+/// only and is not declared as a module (see its doc comment for what's
+/// missing). On non-NVIDIA hardware this
+/// crate currently has no GPU segment prover at all.
+///
+/// Returns an error rather than panicking when the `eval_check` module
+/// can't be loaded on the chosen device (e.g. the fatbin has no cubin for
+/// it and the PTX JIT also fails) — that's a real, recoverable-by-the-
+/// caller condition, not a programmer error.
+pub fn get_segment_prover() -> anyhow::Result<Box<dyn SegmentProver>> {
+    match Device::num_devices() {
+        Ok(count) if count > 1 => multi_gpu_segment_prover(count),
+        _ => single_gpu_segment_prover(),
+    }
+}
+
+fn single_gpu_segment_prover() -> anyhow::Result<Box<dyn SegmentProver>> {
     let hal = Rc::new(CudaHalSha256::new());
-    let circuit_hal = Rc::new(CudaCircuitHalSha256::new(hal.clone()));
-    Box::new(SegmentProverImpl::new(hal, circuit_hal))
+    let circuit_hal = Rc::new(CudaCircuitHalSha256::new(hal.clone())?);
+    Ok(Box::new(SegmentProverImpl::new(hal, circuit_hal)))
+}
+
+/// Builds one worker per visible CUDA device and wraps them in a
+/// [`MultiGpuSegmentProver`] that dispatches segments to them round-robin.
+/// A device that fails to enumerate or to spin up its worker (module
+/// load/JIT failure, context creation failure, ...) is skipped with a
+/// warning rather than aborting the whole prover; if every device fails
+/// this way, falls back to [`single_gpu_segment_prover`] so the caller
+/// still sees a proper error instead of an empty worker pool.
+fn multi_gpu_segment_prover(device_count: u32) -> anyhow::Result<Box<dyn SegmentProver>> {
+    let workers = (0..device_count)
+        .filter_map(|ordinal| {
+            let worker = Device::get_device(ordinal)
+                .map_err(anyhow::Error::from)
+                .and_then(GpuWorker::spawn);
+            match worker {
+                Ok(worker) => Some(worker),
+                Err(err) => {
+                    tracing::warn!("skipping CUDA device {ordinal}: {err}");
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if workers.is_empty() {
+        return single_gpu_segment_prover();
+    }
+    // Wrap whatever workers spun up successfully directly, even if only one
+    // survived: falling back to `single_gpu_segment_prover` here would
+    // discard that worker and re-run device selection from scratch via the
+    // no-device-ordinal path, which may not even land on the same device.
+    Ok(Box::new(MultiGpuSegmentProver::new(workers)))
+}
+
+/// A unit of work handed to a [`GpuWorker`]: prove `segment` and send the
+/// result back on `reply`.
+struct GpuJob {
+    segment: Segment,
+    reply: std::sync::mpsc::Sender<anyhow::Result<SegmentReceipt>>,
+}
+
+/// Owns one physical GPU's `CudaHal`/`CudaCircuitHal` pair on a dedicated
+/// thread. `CudaCircuitHal` retains an `Rc<CudaHal>` to keep its CUDA
+/// context alive, and `Rc` isn't `Send`, so each device's HAL stack has to
+/// live and die on the single thread that created it rather than being
+/// shared across a thread pool.
+struct GpuWorker {
+    sender: std::sync::mpsc::Sender<GpuJob>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl GpuWorker {
+    /// Spawns the worker thread and blocks until it has either bound its
+    /// device context and loaded the `eval_check` module, or failed to.
+    /// Failures (context creation, module load/JIT) are sent back over
+    /// `ready_rx` and surfaced here as a proper `Result` instead of
+    /// panicking on the worker thread, where a panic would otherwise only
+    /// become visible to the caller indirectly, via a later `prove_segment`
+    /// call failing with a disconnected-channel error.
+    fn spawn(device: Device) -> anyhow::Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel::<GpuJob>();
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel::<anyhow::Result<()>>();
+
+        let handle = std::thread::spawn(move || {
+            let init = (|| -> anyhow::Result<_> {
+                // Bind this device's context on the worker thread before
+                // touching any CUDA API; CudaHal::new() operates on
+                // whatever context is current on the calling thread.
+                let context = Context::new(device)?;
+                context.set_current()?;
+
+                let hal = Rc::new(CudaHalSha256::new());
+
+                // `CudaHalSha256::new()` takes no device parameter — it
+                // relies entirely on picking up the context we just bound
+                // above. Confirm that actually happened instead of taking it
+                // on faith: if this ever logs a different ordinal than
+                // `device`, every worker is silently proving on whatever
+                // device `new()` defaults to, and this whole multi-GPU path
+                // is dead weight.
+                let bound = cust::context::CurrentContext::get_device()?;
+                if bound != device {
+                    anyhow::bail!(
+                        "requested CUDA device {device:?} but {bound:?} is current on this \
+                         thread after CudaHalSha256::new(); refusing to silently prove on the wrong GPU"
+                    );
+                }
+
+                let circuit_hal = Rc::new(CudaCircuitHalSha256::new(hal.clone())?);
+                Ok(SegmentProverImpl::new(hal, circuit_hal))
+            })();
+
+            let prover = match init {
+                Ok(prover) => {
+                    let _ = ready_tx.send(Ok(()));
+                    prover
+                }
+                Err(err) => {
+                    let _ = ready_tx.send(Err(err));
+                    return;
+                }
+            };
+
+            for job in receiver {
+                // A panic out of `prove_segment` (e.g. an `.unwrap()` on a
+                // transient driver error) must not kill this loop: doing so
+                // would permanently strand this device, turning every
+                // future job routed here into a disconnected-channel panic
+                // on the caller's side instead of the `Result::Err` the
+                // `SegmentProver` trait promises.
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    prover.prove_segment(&job.segment)
+                }))
+                .unwrap_or_else(|panic| Err(anyhow::anyhow!("GPU worker panicked: {}", panic_message(&panic))));
+                let _ = job.reply.send(result);
+            }
+        });
+
+        ready_rx
+            .recv()
+            .map_err(|_| anyhow::anyhow!("GPU worker thread terminated before initializing"))??;
+
+        Ok(Self {
+            sender,
+            handle: Some(handle),
+        })
+    }
+
+    fn prove_segment(&self, segment: &Segment) -> anyhow::Result<SegmentReceipt> {
+        let (reply_tx, reply_rx) = std::sync::mpsc::channel();
+        self.sender
+            .send(GpuJob {
+                segment: segment.clone(),
+                reply: reply_tx,
+            })
+            .expect("GPU worker thread terminated unexpectedly");
+        reply_rx
+            .recv()
+            .expect("GPU worker thread terminated unexpectedly")
+    }
+}
+
+/// Best-effort extraction of a message from a `catch_unwind` payload, which
+/// is almost always a `&str` or `String` (what `panic!`/`.unwrap()` produce)
+/// but is typed `Box<dyn Any + Send>` since a panic payload can technically
+/// be anything.
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = panic.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = panic.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+impl Drop for GpuWorker {
+    fn drop(&mut self) {
+        // Dropping `sender` (implicitly, as a field of `self`) closes the
+        // channel, which ends the worker's receive loop.
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Dispatches segments to a fixed set of per-device [`GpuWorker`]s,
+/// round-robin, so independent segments prove concurrently across every
+/// visible GPU.
+pub struct MultiGpuSegmentProver {
+    workers: Vec<GpuWorker>,
+    next: std::sync::atomic::AtomicUsize,
+}
+
+impl MultiGpuSegmentProver {
+    fn new(workers: Vec<GpuWorker>) -> Self {
+        Self {
+            workers,
+            next: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+}
+
+impl SegmentProver for MultiGpuSegmentProver {
+    /// Proves one segment on whichever device is next in the round-robin.
+    /// Note this alone does not parallelize anything: `prove_segment`
+    /// blocks until that device replies, so a caller that proves segments
+    /// one at a time through this method, sequentially, only ever keeps a
+    /// single GPU busy. Use [`MultiGpuSegmentProver::prove_segments`] to
+    /// actually fan a batch out across every device concurrently.
+    fn prove_segment(&self, segment: &Segment) -> anyhow::Result<SegmentReceipt> {
+        let idx =
+            self.next.fetch_add(1, std::sync::atomic::Ordering::Relaxed) % self.workers.len();
+        self.workers[idx].prove_segment(segment)
+    }
+}
+
+impl MultiGpuSegmentProver {
+    /// Proves every segment in `segments` concurrently, dispatching
+    /// round-robin across the per-device workers so independent segments
+    /// genuinely run at the same time instead of queueing behind each
+    /// other on the caller's thread. This is the entry point that actually
+    /// delivers the near-linear multi-GPU scaling `MultiGpuSegmentProver`
+    /// exists for; `SegmentProver::prove_segment` is required by its trait
+    /// to be a single blocking call and can't provide that on its own.
+    pub fn prove_segments(&self, segments: &[Segment]) -> Vec<anyhow::Result<SegmentReceipt>> {
+        std::thread::scope(|scope| {
+            segments
+                .iter()
+                .enumerate()
+                .map(|(i, segment)| {
+                    let worker = &self.workers[i % self.workers.len()];
+                    scope.spawn(move || worker.prove_segment(segment))
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -152,7 +710,7 @@ mod tests {
         let cpu_hal: CpuHal<BabyBear> = CpuHal::new(Sha256HashSuite::new_suite());
         let cpu_eval = CpuCircuitHal::new();
         let gpu_hal = Rc::new(CudaHalSha256::new());
-        let gpu_eval = super::CudaCircuitHal::new(gpu_hal.clone());
+        let gpu_eval = super::CudaCircuitHal::new(gpu_hal.clone()).unwrap();
         crate::prove::hal::testutil::eval_check(
             &cpu_hal,
             cpu_eval,
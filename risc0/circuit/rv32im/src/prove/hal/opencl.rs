@@ -0,0 +1,45 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-vendor GPU backend for `eval_check`, for AMD/Intel hardware that
+//! can't load the CUDA fatbin in [`super::cuda`].
+//!
+//! This is not wired up yet. It needs, none of which exist in this crate
+//! today:
+//!   - an OpenCL `Hal` impl in `risc0_zkp` (the CUDA backend builds on
+//!     `risc0_zkp::hal::cuda::CudaHal`; there is no `risc0_zkp::hal::opencl`
+//!     counterpart to build this on top of)
+//!   - an OpenCL C port of the `eval_check` kernel, plus a build-time step
+//!     that templates the BabyBear field arithmetic into it the way the
+//!     CUDA kernel is generated (`RV32IM_CUDA_PATH`)
+//!   - a `rust-gpu-tools` (or similar) dependency for device discovery and
+//!     program loading
+//!   - `pub mod opencl;` added wherever `hal/cuda.rs` is currently declared
+//!     a module, so this file is even reachable
+//!
+//! Tracked as a follow-up; don't build a `CircuitHal` impl against an API
+//! that doesn't exist yet just to make this file look further along than
+//! it is.
+//!
+//! This is prerequisite groundwork only and is not reachable from
+//! [`super::cuda::get_segment_prover`] — it doesn't dispatch to OpenCL, on
+//! this hardware or any other. Don't read the existence of this module as
+//! "OpenCL support landed"; nothing in this crate selects it yet, and
+//! nothing can until the prerequisites above exist.
+
+#[cfg(feature = "opencl")]
+compile_error!(
+    "the \"opencl\" feature is a placeholder: risc0_zkp has no OpenCL Hal yet, \
+     see the module docs in prove/hal/opencl.rs"
+);
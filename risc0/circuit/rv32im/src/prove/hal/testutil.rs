@@ -0,0 +1,139 @@
+// Copyright 2024 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared helpers for cross-checking a GPU `CircuitHal` against the CPU
+//! reference implementation.
+
+use risc0_core::field::{
+    baby_bear::{BabyBearElem, BabyBearExtElem},
+    Elem, ExtElem,
+};
+use risc0_zkp::{
+    hal::{Buffer, CircuitHal, Hal},
+    INV_RATE,
+};
+
+use crate::{GLOBAL_MIX, GLOBAL_OUT, REGISTER_GROUP_ACCUM, REGISTER_GROUP_CTRL, REGISTER_GROUP_DATA};
+
+const REGISTER_GROUPS: usize = 3;
+const GLOBALS: usize = 2;
+
+/// Runs `eval_check` on both `hal1`/`eval1` and `hal2`/`eval2` (typically
+/// CPU and GPU) over matching, pseudo-randomly generated inputs for the
+/// given `po2`, and asserts the two `check` outputs are bit-for-bit equal.
+pub fn eval_check<H1, C1, H2, C2>(hal1: &H1, eval1: C1, hal2: &H2, eval2: C2, po2: usize)
+where
+    H1: Hal<Elem = BabyBearElem, ExtElem = BabyBearExtElem>,
+    C1: CircuitHal<H1>,
+    H2: Hal<Elem = BabyBearElem, ExtElem = BabyBearExtElem>,
+    C2: CircuitHal<H2>,
+{
+    let mut seed = (po2 as u64) ^ 0x9e3779b97f4a7c15;
+    let input = GeneratedInput::new(po2, &mut seed);
+    assert_eq!(run(hal1, &eval1, &input), run(hal2, &eval2, &input));
+}
+
+/// A fully-formed, legal set of `eval_check` inputs: register-group buffers
+/// (ctrl/data/accum), globals (mix/out), a `poly_mix` challenge and `po2`.
+/// Used both by the deterministic test above and by the differential fuzz
+/// target, which builds one of these from `arbitrary` bytes instead of a
+/// PRNG seed.
+pub struct GeneratedInput {
+    pub po2: usize,
+    pub steps: usize,
+    pub groups: [Vec<BabyBearElem>; REGISTER_GROUPS],
+    pub globals: [Vec<BabyBearElem>; GLOBALS],
+    pub poly_mix: BabyBearExtElem,
+}
+
+impl GeneratedInput {
+    pub fn new(po2: usize, seed: &mut u64) -> Self {
+        let steps = 1 << po2;
+        let domain = steps * INV_RATE;
+        Self {
+            po2,
+            steps,
+            groups: [
+                gen_elems(domain, seed),
+                gen_elems(domain, seed),
+                gen_elems(domain, seed),
+            ],
+            globals: [gen_elems(domain, seed), gen_elems(domain, seed)],
+            poly_mix: BabyBearExtElem::from_subelems(gen_elems(BabyBearExtElem::EXT_SIZE, seed)),
+        }
+    }
+}
+
+fn run<H, C>(hal: &H, eval: &C, input: &GeneratedInput) -> Vec<BabyBearElem>
+where
+    H: Hal<Elem = BabyBearElem, ExtElem = BabyBearExtElem>,
+    C: CircuitHal<H>,
+{
+    let domain = input.steps * INV_RATE;
+    let check = hal.alloc_elem("check", domain);
+
+    let ctrl = hal.copy_from_elem("ctrl", &input.groups[REGISTER_GROUP_CTRL]);
+    let data = hal.copy_from_elem("data", &input.groups[REGISTER_GROUP_DATA]);
+    let accum = hal.copy_from_elem("accum", &input.groups[REGISTER_GROUP_ACCUM]);
+    let mix = hal.copy_from_elem("mix", &input.globals[GLOBAL_MIX]);
+    let out = hal.copy_from_elem("out", &input.globals[GLOBAL_OUT]);
+
+    let mut groups = [None, None, None];
+    groups[REGISTER_GROUP_CTRL] = Some(&ctrl);
+    groups[REGISTER_GROUP_DATA] = Some(&data);
+    groups[REGISTER_GROUP_ACCUM] = Some(&accum);
+    let groups: Vec<_> = groups.into_iter().map(|g| g.unwrap()).collect();
+
+    let mut globals = [None, None];
+    globals[GLOBAL_MIX] = Some(&mix);
+    globals[GLOBAL_OUT] = Some(&out);
+    let globals: Vec<_> = globals.into_iter().map(|g| g.unwrap()).collect();
+
+    eval.eval_check(&check, &groups, &globals, input.poly_mix, input.po2, input.steps);
+
+    check.to_vec()
+}
+
+/// Fuzz entry point: asserts `eval1` and `eval2` (CPU and CUDA, in
+/// practice) agree bit-for-bit on `input`. `GeneratedInput` itself has no
+/// `arbitrary::Arbitrary` impl here — building one from raw fuzzer bytes is
+/// the `fuzz` crate's job, so this crate doesn't need an optional
+/// `arbitrary` dependency or feature just to support it.
+pub fn eval_check_fuzz<H1, C1, H2, C2>(
+    hal1: &H1,
+    eval1: &C1,
+    hal2: &H2,
+    eval2: &C2,
+    input: &GeneratedInput,
+) where
+    H1: Hal<Elem = BabyBearElem, ExtElem = BabyBearExtElem>,
+    C1: CircuitHal<H1>,
+    H2: Hal<Elem = BabyBearElem, ExtElem = BabyBearExtElem>,
+    C2: CircuitHal<H2>,
+{
+    assert_eq!(run(hal1, eval1, input), run(hal2, eval2, input));
+}
+
+/// Deterministic pseudo-random `BabyBearElem`s, seeded so repeated calls
+/// with the same `seed` state reproduce the same sequence across hals.
+fn gen_elems(len: usize, seed: &mut u64) -> Vec<BabyBearElem> {
+    // BabyBear modulus (2^31 - 2^27 + 1).
+    const P: u64 = 2013265921;
+    (0..len)
+        .map(|_| {
+            *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            BabyBearElem::new(((*seed >> 33) % P) as u32)
+        })
+        .collect()
+}